@@ -8,7 +8,8 @@
 //! git = "https://github.com/serenity-rs/serenity.git"
 //! features = ["framework", "standard_framework"]
 //! ```
-use std::{collections::{HashMap, HashSet}, fs, fmt::Write, sync::Arc};
+use std::{collections::{HashMap, HashSet}, fs, fmt::Write, sync::Arc, thread, time::Duration as StdDuration};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use serenity::{
     client::bridge::gateway::{ShardId, ShardManager},
     framework::standard::{
@@ -16,9 +17,10 @@ use serenity::{
         DispatchError, HelpOptions, help_commands, StandardFramework,
         macros::{command, group, help, check},
     },
-    model::{channel::{Channel, Message}, gateway::Ready, id::UserId},
+    model::{channel::{Channel, Message}, gateway::Ready, guild::Member, id::{ChannelId, GuildId, UserId}},
     utils::{content_safe, ContentSafeOptions},
 };
+use serde::{Deserialize, Serialize};
 
 // This imports `typemap`'s `Key` as `TypeMapKey`.
 use serenity::prelude::*;
@@ -34,10 +36,198 @@ impl TypeMapKey for ShardManagerContainer {
 
 struct CommandCounter;
 
-impl TypeMapKey for CommandCounter { 
+impl TypeMapKey for CommandCounter {
     type Value = HashMap<String, u64>;
 }
 
+// The file the command counter is persisted to, so that usage statistics
+// survive a restart instead of resetting every time the process is started.
+const COMMAND_COUNTER_FILE: &str = "command_counter.json";
+
+// Loads the persisted command counter from disk. A missing or corrupt file
+// is treated the same as a fresh bot: we just fall back to an empty map
+// instead of failing startup over it.
+fn load_command_counter() -> HashMap<String, u64> {
+    fs::read_to_string(COMMAND_COUNTER_FILE)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+// Flushes the command counter to disk. This is called after every increment;
+// the file is tiny, so there's no need to throttle the writes.
+fn save_command_counter(counter: &HashMap<String, u64>) {
+    match serde_json::to_string_pretty(counter) {
+        Ok(contents) => {
+            if let Err(why) = fs::write(COMMAND_COUNTER_FILE, contents) {
+                println!("Error saving command counter: {:?}", why);
+            }
+        },
+        Err(why) => println!("Error serializing command counter: {:?}", why),
+    }
+}
+
+struct ReminderStore;
+
+impl TypeMapKey for ReminderStore {
+    // Shared with the background dispatch thread spawned in `main`, so both
+    // the `remind` command and the thread see the same pending reminders.
+    type Value = Arc<Mutex<Vec<Reminder>>>;
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Reminder {
+    channel_id: u64,
+    user_id: u64,
+    fire_at: DateTime<Utc>,
+    text: String,
+}
+
+// The file pending reminders are persisted to, so a restart doesn't drop
+// them on the floor.
+const REMINDERS_FILE: &str = "reminders.json";
+
+fn load_reminders() -> Vec<Reminder> {
+    fs::read_to_string(REMINDERS_FILE)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_reminders(reminders: &[Reminder]) {
+    match serde_json::to_string_pretty(reminders) {
+        Ok(contents) => {
+            if let Err(why) = fs::write(REMINDERS_FILE, contents) {
+                println!("Error saving reminders: {:?}", why);
+            }
+        },
+        Err(why) => println!("Error serializing reminders: {:?}", why),
+    }
+}
+
+// Reminders are capped at one year out. Besides being a sane limit for a
+// reminder, it keeps the unit multiplication below well clear of `i64`
+// overflow regardless of how many digits were typed.
+const MAX_REMINDER_DAYS: i64 = 365;
+
+// Parses a single-unit duration like "10m", "2h", "1d", or "45s" into a
+// `chrono::Duration`, matching the `;remind <duration> <message>` usage.
+fn parse_duration(input: &str) -> Option<ChronoDuration> {
+    let split_at = input.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = input.split_at(split_at);
+    let amount: i64 = amount.parse().ok()?;
+
+    let max_amount = match unit {
+        "s" => MAX_REMINDER_DAYS * 24 * 60 * 60,
+        "m" => MAX_REMINDER_DAYS * 24 * 60,
+        "h" => MAX_REMINDER_DAYS * 24,
+        "d" => MAX_REMINDER_DAYS,
+        _ => return None,
+    };
+
+    if amount <= 0 || amount > max_amount {
+        return None;
+    }
+
+    match unit {
+        "s" => Some(ChronoDuration::seconds(amount)),
+        "m" => Some(ChronoDuration::minutes(amount)),
+        "h" => Some(ChronoDuration::hours(amount)),
+        "d" => Some(ChronoDuration::days(amount)),
+        _ => unreachable!(),
+    }
+}
+
+// The file operators edit to deny specific users or guilds outright. Kept
+// separate from `command_counter.json` since one is operator-authored
+// configuration and the other is bot-written metrics.
+const BLOCKLIST_FILE: &str = "blocklist.json";
+
+#[derive(Default, Deserialize)]
+struct Blocklist {
+    #[serde(default)]
+    users: HashSet<u64>,
+    #[serde(default)]
+    guilds: HashSet<u64>,
+}
+
+// Loads the static deny-list from disk. A missing or corrupt file just means
+// no one is blocked yet, same as a fresh install.
+fn load_blocklist() -> Blocklist {
+    fs::read_to_string(BLOCKLIST_FILE)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+// Discord's hard cap on a single message's content length.
+const MESSAGE_CHAR_LIMIT: usize = 2000;
+// The triple-backtick fence on either side of a code block costs 6 chars for
+// the backticks plus 2 newlines; budget for it up front.
+const CODE_BLOCK_OVERHEAD: usize = 8;
+
+// Splits `content` on line boundaries and greedily packs the lines into
+// messages, wrapping each in its own code block, then sends them to
+// `channel_id` in order. A line too long for one message is hard-split into
+// messages of its own. This is how long output (e.g. the full
+// `commands` usage table) avoids ever running into Discord's 2000-character
+// message limit.
+fn send_split_in_cards(ctx: &Context, channel_id: ChannelId, content: &str) {
+    let budget = MESSAGE_CHAR_LIMIT - CODE_BLOCK_OVERHEAD;
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for line in content.lines() {
+        // A single line that alone exceeds the budget has no line boundary
+        // left to break on, so it has to be hard-split instead.
+        if line.len() > budget {
+            if !current.is_empty() {
+                chunks.push(current.split_off(0));
+            }
+
+            // Pack whole `char`s rather than raw bytes, so a hard split
+            // never lands inside a multi-byte character.
+            let mut hard_chunk = String::new();
+
+            for ch in line.chars() {
+                if hard_chunk.len() + ch.len_utf8() > budget {
+                    chunks.push(std::mem::take(&mut hard_chunk));
+                }
+
+                hard_chunk.push(ch);
+            }
+
+            if !hard_chunk.is_empty() {
+                chunks.push(hard_chunk);
+            }
+
+            continue;
+        }
+
+        let separator_len = if current.is_empty() { 0 } else { 1 };
+
+        if current.len() + separator_len + line.len() > budget {
+            chunks.push(current.split_off(0));
+        }
+
+        if !current.is_empty() {
+            current.push('\n');
+        }
+
+        current.push_str(line);
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    for chunk in chunks {
+        if let Err(why) = channel_id.say(&ctx.http, format!("```\n{}\n```", chunk)) {
+            println!("Error sending message: {:?}", why);
+        }
+    }
+}
+
 struct Handler;
 
 impl EventHandler for Handler {
@@ -128,7 +318,7 @@ impl EventHandler for Handler {
 #[description = "General"]
 // Sets a command that will be executed if only a group-prefix was passed.
 // #[default_command(bird)]
-#[commands(ping, latency, commands)]
+#[commands(ping, latency, commands, remind)]
 // Sets a `#[group]` to owners of the bot only.
 // #[owners_only]
 // Limit all commands to be guild-restricted.
@@ -137,6 +327,35 @@ impl EventHandler for Handler {
 // #[checks(Admin)]
 struct General;
 
+// A reusable check that only passes the author holds the `ADMINISTRATOR`
+// permission in the guild the command was invoked in, so groups/commands can
+// be gated on it declaratively via `#[checks(Admin)]` instead of each
+// command re-checking permissions by hand.
+#[check]
+#[name = "Admin"]
+fn admin_check(ctx: &mut Context, msg: &Message, _: &mut Args, _: &CommandOptions) -> CheckResult {
+    // Cache-only lookup: a cache miss (or a transient gap in it) should just
+    // fail this check, not fall through to a blocking HTTP call.
+    let member = match msg.member(&ctx.cache) {
+        Some(member) => member,
+        None => return false.into(),
+    };
+
+    match member.permissions(&ctx.cache) {
+        Ok(permissions) => permissions.administrator().into(),
+        Err(_) => false.into(),
+    }
+}
+
+#[group]
+#[description = "Moderation commands that act on guild members."]
+#[commands(kick, ban)]
+// Limit all commands to be guild-restricted, since members only make
+// sense in the context of a guild.
+#[only_in(guilds)]
+#[checks(Admin)]
+struct Admin;
+
 // The framework provides two built-in help commands for you to use.
 // But you can also make your own customized help command that forwards
 // to the behaviour of either of them.
@@ -193,10 +412,57 @@ fn main() {
     // by Discord for bot users.
     let mut client = Client::new(&token, Handler).expect("Err creating client");
 
+    let reminders = Arc::new(Mutex::new(load_reminders()));
+
     {
         let mut data = client.data.write();
-        data.insert::<CommandCounter>(HashMap::default());
+        data.insert::<CommandCounter>(load_command_counter());
         data.insert::<ShardManagerContainer>(Arc::clone(&client.shard_manager));
+        data.insert::<ReminderStore>(Arc::clone(&reminders));
+    }
+
+    // Background dispatch loop: periodically wake up, send any reminders
+    // whose time has come via the shared `CacheAndHttp`, and persist what's
+    // left. This runs for as long as the bot does.
+    {
+        let reminders = Arc::clone(&reminders);
+        let cache_and_http = Arc::clone(&client.cache_and_http);
+
+        thread::spawn(move || loop {
+            thread::sleep(StdDuration::from_secs(15));
+
+            let due = {
+                let mut pending = reminders.lock();
+                let now = Utc::now();
+                let mut due = Vec::new();
+                let mut still_pending = Vec::new();
+
+                for reminder in pending.drain(..) {
+                    if reminder.fire_at <= now {
+                        due.push(reminder);
+                    } else {
+                        still_pending.push(reminder);
+                    }
+                }
+
+                *pending = still_pending;
+                due
+            };
+
+            if due.is_empty() {
+                continue;
+            }
+
+            for reminder in &due {
+                let content = format!("<@{}> {}", reminder.user_id, reminder.text);
+
+                if let Err(why) = ChannelId(reminder.channel_id).say(&cache_and_http.http, content) {
+                    println!("Error sending reminder: {:?}", why);
+                }
+            }
+
+            save_reminders(&reminders.lock());
+        });
     }
 
     // We will fetch your bot's owners and id
@@ -210,6 +476,11 @@ fn main() {
         Err(why) => panic!("Could not access application info: {:?}", why),
     };
 
+    // Load the static deny-list operators maintain in `blocklist.json`.
+    let blocklist = load_blocklist();
+    let blocked_users: HashSet<UserId> = blocklist.users.into_iter().map(UserId).collect();
+    let blocked_guilds: HashSet<GuildId> = blocklist.guilds.into_iter().map(GuildId).collect();
+
     // Commands are equivalent to:
     // "~about"
     // "~emoji cat"
@@ -240,7 +511,12 @@ fn main() {
             .delimiters(vec![", ", ","])
             // Sets the bot's owners. These will be used for commands that
             // are owners only.
-            .owners(owners))
+            .owners(owners)
+            // Messages from these users, or from guilds they own, are
+            // dropped before dispatch.
+            .blocked_users(blocked_users)
+            // Messages in these guilds are dropped before dispatch.
+            .blocked_guilds(blocked_guilds))
 
         // Set a function to be called prior to each command execution. This
         // provides the context of the command, the message that was received,
@@ -260,6 +536,7 @@ fn main() {
             let counter = data.get_mut::<CommandCounter>().expect("Expected CommandCounter in ShareMap.");
             let entry = counter.entry(command_name.to_string()).or_insert(0);
             *entry += 1;
+            save_command_counter(counter);
 
             true // if `before` returns false, command processing doesn't happen.
         })
@@ -297,6 +574,7 @@ fn main() {
         // They're made in the pattern: `#name_GROUP` for the group instance and `#name_GROUP_OPTIONS`.
         // #name is turned all uppercase
         .group(&GENERAL_GROUP)
+        .group(&ADMIN_GROUP)
     );
 
     // Finally, start a single shard, and start listening to events.
@@ -323,10 +601,98 @@ fn commands(ctx: &mut Context, msg: &Message) -> CommandResult {
         let _ = write!(contents, "- {name}: {amount}\n", name=k, amount=v);
     }
 
-    if let Err(why) = msg.channel_id.say(&ctx.http, &contents) {
-        println!("Error sending message: {:?}", why);
+    send_split_in_cards(ctx, msg.channel_id, &contents);
+
+    Ok(())
+}
+
+// Compares `invoker` and `target` by the position of their highest role, so
+// that moderation commands can refuse to act on someone who outranks (or
+// ties) the person issuing the command. A target with no roles is always
+// actionable, while an invoker with no roles can never act on anyone else,
+// which keeps a role-less invoker from ever being treated as "top rank".
+fn invoker_outranks_target(ctx: &Context, invoker: &Member, target: &Member) -> bool {
+    let invoker_position = invoker.highest_role_info(&ctx.cache).map(|(_, position)| position);
+    let target_position = target.highest_role_info(&ctx.cache).map(|(_, position)| position);
+
+    match (invoker_position, target_position) {
+        (Some(invoker_position), Some(target_position)) => invoker_position > target_position,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions(KICK_MEMBERS)]
+fn kick(ctx: &mut Context, msg: &Message) -> CommandResult {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+
+    let target = match msg.mentions.first() {
+        Some(target) => target,
+        None => {
+            let _ = msg.channel_id.say(&ctx.http, "You must mention a member to kick.");
+
+            return Ok(());
+        },
+    };
+
+    let invoker = guild_id.member(&ctx.http, msg.author.id)?;
+    let target_member = guild_id.member(&ctx.http, target.id)?;
+
+    if !invoker_outranks_target(ctx, &invoker, &target_member) {
+        let _ = msg.channel_id.say(
+            &ctx.http,
+            format!("You don't outrank {} highly enough to kick them.", target_member.display_name()),
+        );
+
+        return Ok(());
     }
 
+    guild_id.kick(&ctx.http, target.id)?;
+
+    let _ = msg.channel_id.say(&ctx.http, format!("Kicked {}.", target_member.display_name()));
+
+    Ok(())
+}
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions(BAN_MEMBERS)]
+fn ban(ctx: &mut Context, msg: &Message) -> CommandResult {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+
+    let target = match msg.mentions.first() {
+        Some(target) => target,
+        None => {
+            let _ = msg.channel_id.say(&ctx.http, "You must mention a member to ban.");
+
+            return Ok(());
+        },
+    };
+
+    let invoker = guild_id.member(&ctx.http, msg.author.id)?;
+    let target_member = guild_id.member(&ctx.http, target.id)?;
+
+    if !invoker_outranks_target(ctx, &invoker, &target_member) {
+        let _ = msg.channel_id.say(
+            &ctx.http,
+            format!("You don't outrank {} highly enough to ban them.", target_member.display_name()),
+        );
+
+        return Ok(());
+    }
+
+    guild_id.ban(&ctx.http, target.id, &0)?;
+
+    let _ = msg.channel_id.say(&ctx.http, format!("Banned {}.", target_member.display_name()));
+
     Ok(())
 }
 
@@ -340,17 +706,13 @@ fn ping(ctx: &mut Context, msg: &Message, args: Args) -> CommandResult {
         // `role_by_name()` allows us to attempt attaining a reference to a role
         // via its name.
         if let Some(role) = guild.read().role_by_name(&potential_role_name) {
-            if let Err(why) = msg.channel_id.say(&ctx.http, &format!("Role-ID: {}", role.id)) {
-                println!("Error sending message: {:?}", why);
-            }
+            send_split_in_cards(ctx, msg.channel_id, &format!("Role-ID: {}", role.id));
 
             return Ok(());
         }
     }
 
-    if let Err(why) = msg.channel_id.say(&ctx.http, format!("Could not find role named: {:?}", potential_role_name)) {
-        println!("Error sending message: {:?}", why);
-    }
+    send_split_in_cards(ctx, msg.channel_id, &format!("Could not find role named: {:?}", potential_role_name));
 
     Ok(())
 }
@@ -387,5 +749,64 @@ fn latency(ctx: &mut Context, msg: &Message) -> CommandResult {
 
     let _ = msg.reply(&ctx, &format!("The shard latency is {:?}", runner.latency));
 
+    Ok(())
+}
+
+#[command]
+fn remind(ctx: &mut Context, msg: &Message, args: Args) -> CommandResult {
+    // This framework is configured with comma delimiters (see
+    // `.delimiters(vec![", ", ","])` above), so `Args` tokenization doesn't
+    // apply to the space-separated `<duration> <message>` syntax here.
+    // Split the raw remainder on the first run of whitespace instead.
+    let remainder = args.rest().trim();
+    let mut parts = remainder.splitn(2, char::is_whitespace);
+    let duration_arg = parts.next().unwrap_or("");
+
+    if duration_arg.is_empty() {
+        let _ = msg.channel_id.say(
+            &ctx.http,
+            "Usage: `;remind <duration> <message>`, e.g. `;remind 10m take a break`.",
+        );
+
+        return Ok(());
+    }
+
+    let duration = match parse_duration(duration_arg) {
+        Some(duration) => duration,
+        None => {
+            let _ = msg.channel_id.say(
+                &ctx.http,
+                format!("Could not parse duration {:?}. Try something like `10m`, `2h`, or `1d`.", duration_arg),
+            );
+
+            return Ok(());
+        },
+    };
+
+    let text = parts.next().unwrap_or("").trim_start().to_string();
+
+    if text.is_empty() {
+        let _ = msg.channel_id.say(&ctx.http, "You must include a message to be reminded of.");
+
+        return Ok(());
+    }
+
+    let reminder = Reminder {
+        channel_id: msg.channel_id.0,
+        user_id: msg.author.id.0,
+        fire_at: Utc::now() + duration,
+        text,
+    };
+
+    {
+        let data = ctx.data.read();
+        let reminders = data.get::<ReminderStore>().expect("Expected ReminderStore in ShareMap.");
+        let mut reminders = reminders.lock();
+        reminders.push(reminder);
+        save_reminders(&reminders);
+    }
+
+    let _ = msg.channel_id.say(&ctx.http, format!("Got it, I'll remind you in {}.", duration_arg));
+
     Ok(())
 }
\ No newline at end of file